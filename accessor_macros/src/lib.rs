@@ -0,0 +1,328 @@
+//! Derive macros for `accessor`.
+//!
+//! This crate is not meant to be used directly; its macros are re-exported from the `accessor`
+//! crate and should be reached through there.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+/// Implements `accessor::single::BoundedStructural` and `BoundedStructuralMut` for a
+/// `#[repr(C)]` struct, generating a struct of single-field accessors with the same field
+/// names.
+///
+/// See [`accessor::single::BoundedStructural`] for details and an example.
+#[proc_macro_derive(BoundedStructuralOf, attributes(access))]
+pub fn bounded_structural_of(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let fields = match struct_fields(&input) {
+        Ok(fields) => fields,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let name = &input.ident;
+    let of_name = format_ident!("BoundedStructuralOf{}", name);
+    let of_struct = field_struct(&of_name, &fields);
+    let field_inits = field_inits(name, &fields);
+
+    let expanded = quote! {
+        #of_struct
+
+        impl<M, A> accessor::single::BoundedStructural<#name, M, A> for accessor::single::Generic<#name, M, A>
+        where
+            M: accessor::mapper::Mapper,
+            A: accessor::marker::Readable,
+        {
+            type BoundedStructuralType<'a> = #of_name<'a> where Self: 'a;
+
+            fn structural(&self) -> Self::BoundedStructuralType<'_> {
+                let base = unsafe { self.ptr() };
+                #of_name {
+                    #(#field_inits,)*
+                    _life: ::core::marker::PhantomData,
+                }
+            }
+        }
+
+        impl<M, A> accessor::single::BoundedStructuralMut<#name, M, A> for accessor::single::Generic<#name, M, A>
+        where
+            M: accessor::mapper::Mapper,
+            A: accessor::marker::Writable,
+        {
+            type BoundedStructuralType<'a> = #of_name<'a> where Self: 'a;
+
+            fn structural_mut(&mut self) -> Self::BoundedStructuralType<'_> {
+                let base = unsafe { self.ptr() };
+                #of_name {
+                    #(#field_inits,)*
+                    _life: ::core::marker::PhantomData,
+                }
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// Implements `accessor::array::BoundSetGeneric` and `BoundSetGenericMut` for a `#[repr(C)]`
+/// struct, generating a struct of single-field accessors bound to one element of an
+/// `accessor::array::Generic`.
+///
+/// See [`accessor::array::BoundSetGeneric`] for details and an example.
+#[proc_macro_derive(BoundSetGenericOf, attributes(access))]
+pub fn bound_set_generic_of(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let fields = match struct_fields(&input) {
+        Ok(fields) => fields,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let name = &input.ident;
+    let of_name = format_ident!("BoundSetGenericOf{}", name);
+    let of_struct = field_struct(&of_name, &fields);
+    let field_inits = field_inits(name, &fields);
+
+    let expanded = quote! {
+        #of_struct
+
+        impl<M, A> accessor::array::BoundSetGeneric<#name, M, A> for accessor::array::Generic<#name, M, A>
+        where
+            M: accessor::mapper::Mapper,
+            A: accessor::marker::Readable,
+        {
+            type BoundSetGenericType<'a> = #of_name<'a> where Self: 'a;
+
+            fn set_at(&self, i: usize) -> Self::BoundSetGenericType<'_> {
+                let base = self.ptr_at(i);
+                #of_name {
+                    #(#field_inits,)*
+                    _life: ::core::marker::PhantomData,
+                }
+            }
+        }
+
+        impl<M, A> accessor::array::BoundSetGenericMut<#name, M, A> for accessor::array::Generic<#name, M, A>
+        where
+            M: accessor::mapper::Mapper,
+            A: accessor::marker::Writable,
+        {
+            type BoundSetGenericType<'a> = #of_name<'a> where Self: 'a;
+
+            fn set_at_mut(&mut self, i: usize) -> Self::BoundSetGenericType<'_> {
+                let base = self.ptr_at(i);
+                #of_name {
+                    #(#field_inits,)*
+                    _life: ::core::marker::PhantomData,
+                }
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// Generates the `#[doc(hidden)]` struct of per-field accessors shared by both derive macros.
+///
+/// Every field is an [`accessor::single::Borrowed`], since the field's virtual address is
+/// already known (it is derived from the base accessor's address plus the field's offset) and
+/// the memory behind it is already mapped by the base accessor; no further mapping is needed.
+fn field_struct(of_name: &syn::Ident, fields: &[FieldInfo<'_>]) -> proc_macro2::TokenStream {
+    let field_decls = fields.iter().map(|f| {
+        let ident = f.ident;
+        let ty = f.ty;
+        let access = f.access.single_type();
+        quote! { pub #ident: accessor::single::Borrowed<'a, #ty, #access> }
+    });
+
+    quote! {
+        #[doc(hidden)]
+        pub struct #of_name<'a> {
+            #(#field_decls,)*
+            _life: ::core::marker::PhantomData<&'a ()>,
+        }
+    }
+}
+
+/// Generates the per-field constructor calls used by both derive macros, each borrowing the
+/// field's already-mapped memory via [`accessor::single::Borrowed::from_ptr`]. Expects a local
+/// variable `base: ::core::ptr::NonNull<u8>` pointing at the struct instance, from which each
+/// field pointer is derived directly so it keeps `base`'s provenance instead of round-tripping
+/// through a `usize` address.
+fn field_inits(name: &syn::Ident, fields: &[FieldInfo<'_>]) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter()
+        .map(|f| {
+            let ident = f.ident;
+            quote! {
+                #ident: unsafe {
+                    accessor::single::Borrowed::from_ptr(
+                        base.add(::core::mem::offset_of!(#name, #ident)).cast(),
+                    )
+                }
+            }
+        })
+        .collect()
+}
+
+struct FieldInfo<'a> {
+    ident: &'a syn::Ident,
+    ty: &'a syn::Type,
+    access: Access,
+}
+
+/// The per-field access mode, set through `#[access(..)]`.
+///
+/// See the `#[access(..)]` attribute docs for details. Defaults to [`Access::ReadWrite`] when
+/// the attribute is absent.
+#[derive(Clone, Copy)]
+enum Access {
+    ReadWrite,
+    ReadOnly,
+    WriteOnly,
+}
+impl Access {
+    fn single_type(self) -> proc_macro2::TokenStream {
+        match self {
+            Self::ReadWrite => quote! { accessor::marker::ReadWrite },
+            Self::ReadOnly => quote! { accessor::marker::ReadOnly },
+            Self::WriteOnly => quote! { accessor::marker::WriteOnly },
+        }
+    }
+}
+
+fn struct_fields(input: &DeriveInput) -> syn::Result<Vec<FieldInfo<'_>>> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "this derive only supports structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "this derive only supports structs with named fields",
+        ));
+    };
+
+    fields
+        .named
+        .iter()
+        .map(|f| {
+            Ok(FieldInfo {
+                ident: f.ident.as_ref().expect("named field has an ident"),
+                ty: &f.ty,
+                access: parse_access(f)?,
+            })
+        })
+        .collect()
+}
+
+/// Parses the `#[access(ReadOnly | WriteOnly | ReadWrite)]` attribute on a field, defaulting to
+/// `ReadWrite` when the attribute is absent.
+fn parse_access(field: &syn::Field) -> syn::Result<Access> {
+    for attr in &field.attrs {
+        if !attr.path().is_ident("access") {
+            continue;
+        }
+        let ident: syn::Ident = attr.parse_args()?;
+        return match ident.to_string().as_str() {
+            "ReadWrite" => Ok(Access::ReadWrite),
+            "ReadOnly" => Ok(Access::ReadOnly),
+            "WriteOnly" => Ok(Access::WriteOnly),
+            other => Err(syn::Error::new_spanned(
+                ident,
+                format!(
+                    "unknown access mode `{other}`; expected `ReadWrite`, `ReadOnly` or `WriteOnly`"
+                ),
+            )),
+        };
+    }
+    Ok(Access::ReadWrite)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn access_of(field: &syn::Field) -> Access {
+        parse_access(field).expect("parse_access should succeed")
+    }
+
+    #[test]
+    fn test_parse_access_defaults_to_read_write() {
+        let field: syn::Field = parse_quote! { x: u32 };
+
+        assert!(matches!(access_of(&field), Access::ReadWrite));
+    }
+
+    #[test]
+    fn test_parse_access_read_only() {
+        let field: syn::Field = parse_quote! {
+            #[access(ReadOnly)]
+            x: u32
+        };
+
+        assert!(matches!(access_of(&field), Access::ReadOnly));
+    }
+
+    #[test]
+    fn test_parse_access_write_only() {
+        let field: syn::Field = parse_quote! {
+            #[access(WriteOnly)]
+            x: u32
+        };
+
+        assert!(matches!(access_of(&field), Access::WriteOnly));
+    }
+
+    #[test]
+    fn test_parse_access_unknown_mode_is_error() {
+        let field: syn::Field = parse_quote! {
+            #[access(Bogus)]
+            x: u32
+        };
+
+        assert!(parse_access(&field).is_err());
+    }
+
+    #[test]
+    fn test_struct_fields_mixed_access() {
+        let input: DeriveInput = parse_quote! {
+            #[repr(C)]
+            struct Regs {
+                #[access(ReadOnly)]
+                status: u32,
+                #[access(WriteOnly)]
+                command: u32,
+                scratch: u32,
+            }
+        };
+
+        let fields = struct_fields(&input).expect("struct_fields should succeed");
+        let idents: Vec<_> = fields.iter().map(|f| f.ident.to_string()).collect();
+        assert_eq!(idents, ["status", "command", "scratch"]);
+
+        assert!(matches!(fields[0].access, Access::ReadOnly));
+        assert!(matches!(fields[1].access, Access::WriteOnly));
+        assert!(matches!(fields[2].access, Access::ReadWrite));
+    }
+
+    #[test]
+    fn test_struct_fields_rejects_enum() {
+        let input: DeriveInput = parse_quote! {
+            enum Foo { Bar }
+        };
+
+        assert!(struct_fields(&input).is_err());
+    }
+
+    #[test]
+    fn test_struct_fields_rejects_tuple_struct() {
+        let input: DeriveInput = parse_quote! {
+            struct Foo(u32);
+        };
+
+        assert!(struct_fields(&input).is_err());
+    }
+}
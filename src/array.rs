@@ -0,0 +1,754 @@
+//! An accessor to an array of elements
+
+use {
+    crate::{
+        error::Error,
+        mapper::Mapper,
+        marker::{self, AccessorTypeSpecifier, Readable, UnsafeReadable, UnsafeWritable, Writable},
+    },
+    core::{cell::UnsafeCell, marker::PhantomData, mem, ptr, ptr::NonNull},
+};
+
+#[doc(inline)]
+pub use accessor_macros::BoundSetGenericOf;
+
+/// An alias of [`ReadWrite`].
+#[deprecated(since = "0.3.2", note = "Use `ReadWrite`.")]
+pub type Array<T, M> = ReadWrite<T, M>;
+
+/// A readable and writable array accessor.
+pub type ReadWrite<T, M> = Generic<T, M, marker::ReadWrite>;
+
+/// A read-only array accessor.
+pub type ReadOnly<T, M> = Generic<T, M, marker::ReadOnly>;
+
+/// A write-only array accessor.
+pub type WriteOnly<T, M> = Generic<T, M, marker::WriteOnly>;
+
+/// An array accessor whose elements are readable, but only inside an `unsafe` block (e.g. a FIFO
+/// register that pops an element on every read).
+pub type UnsafeReadOnly<T, M> = Generic<T, M, marker::UnsafeReadOnly>;
+
+/// An array accessor whose elements are writable, but only inside an `unsafe` block (e.g. a
+/// status register that clears bits on every write).
+pub type UnsafeWriteOnly<T, M> = Generic<T, M, marker::UnsafeWriteOnly>;
+
+/// An array accessor whose elements are neither readable nor writable, safe or otherwise.
+pub type NoAccess<T, M> = Generic<T, M, marker::NoAccess>;
+
+/// Combined with proc-macro [`BoundSetGenericOf`], this trait converts an array accessor of a
+/// field struct type into a struct of field accessors bound to a single element of the array.
+///
+/// This trait is intended to be implemented automatically by [`BoundSetGenericOf`] macro
+/// expansion. Users should not implement this manually.
+///
+/// [`BoundSetGenericOf`]: crate::array::BoundSetGenericOf
+pub trait BoundSetGeneric<T, M, A>
+where
+    M: Mapper,
+    A: Readable,
+{
+    /// The concrete type of the struct of accessors which `.set_at(i)` returns.
+    type BoundSetGenericType<'a>
+    where
+        Self: 'a;
+
+    /// Returns a struct of accessors bound to the `i`-th element of the array, each with the
+    /// access mode of its field.
+    fn set_at(&self, i: usize) -> Self::BoundSetGenericType<'_>;
+}
+
+/// The mutable counterpart for [`BoundSetGeneric`].
+/// See [`BoundSetGeneric`] for details.
+pub trait BoundSetGenericMut<T, M, A>
+where
+    M: Mapper,
+    A: Writable,
+{
+    /// The concrete type of the struct of accessors which `.set_at_mut(i)` returns.
+    type BoundSetGenericType<'a>
+    where
+        Self: 'a;
+
+    /// Returns a struct of accessors bound to the `i`-th element of the array, each with the
+    /// access mode of its field.
+    fn set_at_mut(&mut self, i: usize) -> Self::BoundSetGenericType<'_>;
+}
+
+/// A borrowed accessor to a single element of an [`array::Generic`], returned by
+/// [`Generic::at`] and [`Generic::at_mut`] — or, for memory the caller has already mapped
+/// itself, by [`Element::from_ptr`].
+///
+/// Unlike [`single::Generic`], this does not own a [`Mapper`] and performs no unmapping on
+/// drop; it simply borrows memory that is already mapped, whether owned by the array accessor
+/// that created it or by the caller of [`Element::from_ptr`].
+///
+/// [`array::Generic`]: crate::array::Generic
+/// [`single::Generic`]: crate::single::Generic
+pub struct Element<'a, T, A> {
+    ptr: NonNull<UnsafeCell<T>>,
+    _readable_writable: PhantomData<A>,
+    _life: PhantomData<&'a ()>,
+}
+impl<'a, T, A> Element<'a, T, A> {
+    fn new(ptr: NonNull<UnsafeCell<T>>) -> Self {
+        Self {
+            ptr,
+            _readable_writable: PhantomData,
+            _life: PhantomData,
+        }
+    }
+
+    /// Creates a borrowed accessor to an element of type `T` at the virtual address `ptr`.
+    ///
+    /// This is useful when the caller already holds a pointer into memory that is mapped (e.g.
+    /// a slot of a `#[repr(C)]` block mapped once as a whole), and wants an accessor without
+    /// the overhead of owning a [`Mapper`] and unmapping on drop.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the following conditions:
+    /// - `ptr` is valid for reads and writes of a `T` for as long as the returned accessor lives.
+    /// - Any other accessors except the one returned by this method must not access the value
+    ///   while the returned one lives.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `ptr` is not aligned as the type `T` requires.
+    pub unsafe fn from_ptr(ptr: NonNull<T>) -> Self {
+        assert!(super::is_aligned::<T>(ptr.as_ptr() as usize));
+
+        Self::new(ptr.cast())
+    }
+}
+impl<'a, T, A> Element<'a, T, A>
+where
+    A: Readable,
+{
+    /// Reads a value from the address that this element accessor points to.
+    pub fn read_volatile(&self) -> T {
+        // SAFETY: `Generic::new_strided` or `Element::from_ptr` ensures that `self.ptr` is aligned
+        // properly and points to a valid `T`. Going through `UnsafeCell::raw_get` avoids
+        // manufacturing a `&T` that could
+        // alias with a write performed through another mapping of the same memory.
+        unsafe { ptr::read_volatile(UnsafeCell::raw_get(self.ptr.as_ptr())) }
+    }
+}
+impl<'a, T, A> Element<'a, T, A>
+where
+    A: Writable,
+{
+    /// Writes a value to the address that this element accessor points to.
+    pub fn write_volatile(&mut self, v: T) {
+        // SAFETY: `Generic::new_strided` or `Element::from_ptr` ensures that `self.ptr` is aligned
+        // properly and points to a valid `T`. Going through `UnsafeCell::raw_get` avoids
+        // manufacturing a `&mut T` that
+        // could alias with an access performed through another mapping of the same memory.
+        unsafe {
+            ptr::write_volatile(UnsafeCell::raw_get(self.ptr.as_ptr()), v);
+        }
+    }
+}
+impl<'a, T, A> Element<'a, T, A>
+where
+    A: Readable + Writable,
+{
+    /// Updates a value that this element accessor points to by reading it, modifying it, and
+    /// writing it back.
+    pub fn update_volatile<U>(&mut self, f: U)
+    where
+        U: FnOnce(&mut T),
+    {
+        let mut v = self.read_volatile();
+        f(&mut v);
+        self.write_volatile(v);
+    }
+}
+impl<'a, T, A> Element<'a, T, A>
+where
+    A: UnsafeReadable,
+{
+    /// Reads a value from the address that this element accessor points to.
+    ///
+    /// # Safety
+    ///
+    /// The marker type `A` grants this access because reading has a side effect (e.g. a FIFO
+    /// register that pops an element on every read). The caller must ensure that performing
+    /// this side effect is sound.
+    pub unsafe fn unsafe_read_volatile(&self) -> T {
+        // SAFETY: `Generic::new_strided` or `Element::from_ptr` ensures that `self.ptr` is aligned
+        // properly and points to a valid `T`. The caller upholds the side-effect safety of the
+        // read itself.
+        unsafe { ptr::read_volatile(UnsafeCell::raw_get(self.ptr.as_ptr())) }
+    }
+}
+impl<'a, T, A> Element<'a, T, A>
+where
+    A: UnsafeWritable,
+{
+    /// Writes a value to the address that this element accessor points to.
+    ///
+    /// # Safety
+    ///
+    /// The marker type `A` grants this access because writing has a side effect (e.g. a status
+    /// register that clears bits on every write). The caller must ensure that performing this
+    /// side effect is sound.
+    pub unsafe fn unsafe_write_volatile(&mut self, v: T) {
+        // SAFETY: `Generic::new_strided` or `Element::from_ptr` ensures that `self.ptr` is aligned
+        // properly and points to a valid `T`. The caller upholds the side-effect safety of the
+        // write itself.
+        unsafe {
+            ptr::write_volatile(UnsafeCell::raw_get(self.ptr.as_ptr()), v);
+        }
+    }
+}
+impl<'a, T, A> Element<'a, T, A>
+where
+    A: UnsafeReadable + UnsafeWritable,
+{
+    /// Updates a value that this element accessor points to by reading it, modifying it, and
+    /// writing it back.
+    ///
+    /// # Safety
+    ///
+    /// See the safety requirements of [`Element::unsafe_read_volatile`] and
+    /// [`Element::unsafe_write_volatile`].
+    pub unsafe fn unsafe_update_volatile<U>(&mut self, f: U)
+    where
+        U: FnOnce(&mut T),
+    {
+        // SAFETY: the caller upholds the safety requirements of both the read and the write.
+        let mut v = unsafe { self.unsafe_read_volatile() };
+        f(&mut v);
+        // SAFETY: see above.
+        unsafe { self.unsafe_write_volatile(v) };
+    }
+}
+
+/// An iterator over read-only accessors to the elements of an [`array::Generic`], created by
+/// [`Generic::iter`].
+///
+/// [`array::Generic`]: crate::array::Generic
+pub struct Iter<'a, T, A> {
+    next: NonNull<UnsafeCell<T>>,
+    stride: usize,
+    remaining: usize,
+    _readable_writable: PhantomData<A>,
+    _life: PhantomData<&'a ()>,
+}
+impl<'a, T, A> Iterator for Iter<'a, T, A>
+where
+    A: Readable,
+{
+    type Item = Element<'a, T, marker::ReadOnly>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let ptr = self.next;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            // SAFETY: `remaining` tracks how many more strides fit in the mapped region after
+            // this element, so advancing by one more stride stays within it.
+            self.next = unsafe {
+                NonNull::new_unchecked(ptr.as_ptr().cast::<u8>().add(self.stride).cast())
+            };
+        }
+        Some(Element::new(ptr))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<'a, T, A> ExactSizeIterator for Iter<'a, T, A> where A: Readable {}
+impl<'a, T, A> core::iter::FusedIterator for Iter<'a, T, A> where A: Readable {}
+
+/// An iterator over writable accessors to the elements of an [`array::Generic`], created by
+/// [`Generic::iter_mut`].
+///
+/// [`array::Generic`]: crate::array::Generic
+pub struct IterMut<'a, T, A> {
+    next: NonNull<UnsafeCell<T>>,
+    stride: usize,
+    remaining: usize,
+    _readable_writable: PhantomData<A>,
+    _life: PhantomData<&'a mut ()>,
+}
+impl<'a, T, A> Iterator for IterMut<'a, T, A>
+where
+    A: Writable,
+{
+    type Item = Element<'a, T, A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let ptr = self.next;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            // SAFETY: `remaining` tracks how many more strides fit in the mapped region after
+            // this element, so advancing by one more stride stays within it.
+            self.next = unsafe {
+                NonNull::new_unchecked(ptr.as_ptr().cast::<u8>().add(self.stride).cast())
+            };
+        }
+        Some(Element::new(ptr))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<'a, T, A> ExactSizeIterator for IterMut<'a, T, A> where A: Writable {}
+impl<'a, T, A> core::iter::FusedIterator for IterMut<'a, T, A> where A: Writable {}
+
+impl<'a, T, M, A> IntoIterator for &'a Generic<T, M, A>
+where
+    M: Mapper,
+    A: Readable,
+{
+    type Item = Element<'a, T, marker::ReadOnly>;
+    type IntoIter = Iter<'a, T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl<'a, T, M, A> IntoIterator for &'a mut Generic<T, M, A>
+where
+    M: Mapper,
+    A: Writable,
+{
+    type Item = Element<'a, T, A>;
+    type IntoIter = IterMut<'a, T, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// An accessor to an array of elements of memory.
+///
+/// # Examples
+///
+/// ```no_run
+/// use accessor::array;
+/// use accessor::mapper::Mapper;
+/// use core::num::NonZeroUsize;
+///
+/// struct M;
+/// impl Mapper for M {
+///     unsafe fn map(&mut self, phys_start: usize, bytes: usize) -> NonZeroUsize {
+///         todo!()
+///     }
+///
+///     fn unmap(&mut self, phys_start: usize, bytes: usize) {
+///         todo!()
+///     }
+/// }
+///
+/// // Create an accessor to 10 `u32` values starting at the physical address 0x1000.
+/// let mut a = unsafe { array::ReadWrite::<u32, M>::new(0x1000, 10, M) };
+///
+/// // Read the 0th element.
+/// a.at(0).read_volatile();
+///
+/// // Write 42 to the 2nd element.
+/// a.at_mut(2).write_volatile(42);
+///
+/// // Iterate over every element.
+/// for reg in a.iter() {
+///     reg.read_volatile();
+/// }
+/// ```
+pub struct Generic<T, M, A>
+where
+    M: Mapper,
+    A: AccessorTypeSpecifier,
+{
+    ptr: NonNull<u8>,
+    len: usize,
+    stride: usize,
+    _marker: PhantomData<T>,
+    _readable_writable: PhantomData<A>,
+    mapper: M,
+}
+impl<T, M, A> Generic<T, M, A>
+where
+    M: Mapper,
+    A: AccessorTypeSpecifier,
+{
+    /// Creates a new array accessor to `len` elements of type `T` starting at the physical
+    /// address `phys_base`, with each element packed at `size_of::<T>()` apart.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the following conditions:
+    /// - The values at the physical address range are valid.
+    /// - Any other accessors except the one returned by this method must not access the values
+    ///   while the returned one lives.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `phys_base` is not aligned as the type `T` requires.
+    pub unsafe fn new(phys_base: usize, len: usize, mapper: M) -> Self {
+        Self::new_strided(phys_base, len, mem::size_of::<T>(), mapper)
+    }
+
+    /// Creates a new array accessor to `len` elements of type `T` starting at the physical
+    /// address `phys_base`, with each element `stride` bytes apart.
+    ///
+    /// This is useful for hardware register arrays where consecutive elements are padded to a
+    /// stride wider than `size_of::<T>()` (e.g. a 4-byte register every 16 bytes).
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the following conditions:
+    /// - The values at the physical address range are valid.
+    /// - Any other accessors except the one returned by this method must not access the values
+    ///   while the returned one lives.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `phys_base` or `stride` is not aligned as the type `T` requires.
+    pub unsafe fn new_strided(phys_base: usize, len: usize, stride: usize, mut mapper: M) -> Self {
+        assert!(super::is_aligned::<T>(phys_base));
+        assert!(super::is_aligned::<T>(stride));
+
+        let bytes = Self::mapped_bytes(len, stride);
+        let virt = mapper.map(phys_base, bytes);
+
+        // SAFETY: `Mapper::map` returns a `NonZeroUsize`, so the resulting pointer is never null.
+        let ptr = unsafe { NonNull::new_unchecked(virt.get() as *mut u8) };
+
+        Self {
+            ptr,
+            len,
+            stride,
+            _marker: PhantomData,
+            _readable_writable: PhantomData,
+            mapper,
+        }
+    }
+
+    /// Creates a new array accessor to `len` elements of type `T` starting at the physical
+    /// address `phys_base`, with each element packed at `size_of::<T>()` apart.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the following conditions:
+    /// - The values at the physical address range are valid.
+    /// - Any other accessors except the one returned by this method must not access the values
+    ///   while the returned one lives.
+    ///
+    /// # Errors
+    ///
+    /// This method may return a [`Error::NotAligned`] error if `phys_base` is not aligned as the
+    /// type `T` requires.
+    pub unsafe fn try_new(phys_base: usize, len: usize, mapper: M) -> Result<Self, Error> {
+        Self::try_new_strided(phys_base, len, mem::size_of::<T>(), mapper)
+    }
+
+    /// Creates a new array accessor to `len` elements of type `T` starting at the physical
+    /// address `phys_base`, with each element `stride` bytes apart.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the following conditions:
+    /// - The values at the physical address range are valid.
+    /// - Any other accessors except the one returned by this method must not access the values
+    ///   while the returned one lives.
+    ///
+    /// # Errors
+    ///
+    /// This method may return a [`Error::NotAligned`] error if `phys_base` or `stride` is not
+    /// aligned as the type `T` requires.
+    pub unsafe fn try_new_strided(
+        phys_base: usize,
+        len: usize,
+        stride: usize,
+        mapper: M,
+    ) -> Result<Self, Error> {
+        if !super::is_aligned::<T>(phys_base) {
+            return Err(Error::NotAligned {
+                alignment: mem::align_of::<T>(),
+                address: phys_base,
+            });
+        }
+        if !super::is_aligned::<T>(stride) {
+            return Err(Error::NotAligned {
+                alignment: mem::align_of::<T>(),
+                address: stride,
+            });
+        }
+        Ok(Self::new_strided(phys_base, len, stride, mapper))
+    }
+
+    /// Returns the number of elements in the array.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the array contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of bytes that must be mapped to cover `len` elements spaced `stride`
+    /// bytes apart.
+    fn mapped_bytes(len: usize, stride: usize) -> usize {
+        if len == 0 {
+            0
+        } else {
+            (len - 1) * stride + mem::size_of::<T>()
+        }
+    }
+
+    /// Returns a pointer to the `i`-th element.
+    ///
+    /// This is public but hidden, since this method should be called in
+    /// `accessor_macros::BoundSetGenericOf` proc-macro expansion to derive per-field pointers
+    /// without losing the provenance of `self.ptr`. Users of this crate are not intended to call
+    /// this directly.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `i >= self.len()`.
+    #[doc(hidden)]
+    pub fn ptr_at(&self, i: usize) -> NonNull<u8> {
+        self.element_ptr(i).cast()
+    }
+
+    /// Returns a pointer to the `i`-th element.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `i >= self.len()`.
+    fn element_ptr(&self, i: usize) -> NonNull<UnsafeCell<T>> {
+        assert!(i < self.len);
+        // SAFETY: `i < self.len` keeps the offset within the region mapped by `Self::new_strided`.
+        unsafe { self.ptr.add(i * self.stride).cast() }
+    }
+}
+impl<T, M, A> Generic<T, M, A>
+where
+    M: Mapper,
+    A: Readable,
+{
+    /// Returns a borrowed accessor to the `i`-th element.
+    pub fn at(&self, i: usize) -> Element<'_, T, A> {
+        Element::new(self.element_ptr(i))
+    }
+
+    /// Returns an iterator over borrowed, read-only accessors to every element of the array, in
+    /// order.
+    pub fn iter(&self) -> Iter<'_, T, A> {
+        Iter {
+            next: self.ptr.cast(),
+            stride: self.stride,
+            remaining: self.len,
+            _readable_writable: PhantomData,
+            _life: PhantomData,
+        }
+    }
+}
+impl<T, M, A> Generic<T, M, A>
+where
+    M: Mapper,
+    A: Writable,
+{
+    /// Returns a mutably borrowed accessor to the `i`-th element.
+    pub fn at_mut(&mut self, i: usize) -> Element<'_, T, A> {
+        Element::new(self.element_ptr(i))
+    }
+
+    /// Returns an iterator over mutably borrowed, writable accessors to every element of the
+    /// array, in order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, A> {
+        IterMut {
+            next: self.ptr.cast(),
+            stride: self.stride,
+            remaining: self.len,
+            _readable_writable: PhantomData,
+            _life: PhantomData,
+        }
+    }
+}
+impl<T, M, A> Generic<T, M, A>
+where
+    M: Mapper,
+    A: UnsafeReadable,
+{
+    /// Returns a borrowed accessor to the `i`-th element, usable only through
+    /// [`Element::unsafe_read_volatile`].
+    pub fn unsafe_at(&self, i: usize) -> Element<'_, T, A> {
+        Element::new(self.element_ptr(i))
+    }
+}
+impl<T, M, A> Generic<T, M, A>
+where
+    M: Mapper,
+    A: UnsafeWritable,
+{
+    /// Returns a mutably borrowed accessor to the `i`-th element, usable only through
+    /// [`Element::unsafe_write_volatile`].
+    pub fn unsafe_at_mut(&mut self, i: usize) -> Element<'_, T, A> {
+        Element::new(self.element_ptr(i))
+    }
+}
+impl<T, M, A> Drop for Generic<T, M, A>
+where
+    M: Mapper,
+    A: AccessorTypeSpecifier,
+{
+    fn drop(&mut self) {
+        let bytes = Self::mapped_bytes(self.len, self.stride);
+        self.mapper.unmap(self.ptr.as_ptr() as usize, bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::num::NonZeroUsize;
+
+    struct M;
+    impl Mapper for M {
+        unsafe fn map(&mut self, phys_start: usize, _: usize) -> NonZeroUsize {
+            NonZeroUsize::new(phys_start).unwrap()
+        }
+
+        fn unmap(&mut self, _: usize, _: usize) {}
+    }
+
+    #[test]
+    fn test_packed_read_write() {
+        let mut v: [u32; 3] = [1, 2, 3];
+        let mut a = unsafe { ReadWrite::<u32, M>::new(addr(&mut v), 3, M) };
+
+        assert_eq!(a.at(0).read_volatile(), 1);
+        assert_eq!(a.at(2).read_volatile(), 3);
+
+        a.at_mut(1).write_volatile(42);
+        assert_eq!(v[1], 42);
+    }
+
+    #[test]
+    fn test_strided_read_write() {
+        // Each `u32` element is placed 16 bytes apart, as in a padded MMIO register array.
+        let mut v: [u32; 12] = [0; 12];
+        let stride = 16;
+        let mut a = unsafe { ReadWrite::<u32, M>::new_strided(addr(&mut v), 3, stride, M) };
+
+        a.at_mut(0).write_volatile(1);
+        a.at_mut(1).write_volatile(2);
+        a.at_mut(2).write_volatile(3);
+
+        assert_eq!(v[0], 1);
+        assert_eq!(v[4], 2);
+        assert_eq!(v[8], 3);
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut v: [u32; 3] = [1, 2, 3];
+        let a = unsafe { ReadWrite::<u32, M>::new(addr(&mut v), 3, M) };
+
+        assert_eq!(a.iter().len(), 3);
+        for (i, e) in a.iter().enumerate() {
+            assert_eq!(e.read_volatile(), v[i]);
+        }
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut v: [u32; 3] = [0, 0, 0];
+        let mut a = unsafe { ReadWrite::<u32, M>::new(addr(&mut v), 3, M) };
+
+        for (i, mut e) in a.iter_mut().enumerate() {
+            e.write_volatile(i as u32 * 10);
+        }
+        assert_eq!(v, [0, 10, 20]);
+    }
+
+    type UnsafeReadWrite<T, M> =
+        Generic<T, M, marker::Access<marker::permission::Unsafe, marker::permission::Unsafe>>;
+
+    #[test]
+    fn test_unsafe_read_write_volatile() {
+        let mut v: [u32; 3] = [1, 2, 3];
+        let mut a = unsafe { UnsafeReadWrite::<u32, M>::new(addr(&mut v), 3, M) };
+
+        assert_eq!(unsafe { a.unsafe_at(0).unsafe_read_volatile() }, 1);
+
+        unsafe { a.unsafe_at_mut(1).unsafe_write_volatile(42) };
+        assert_eq!(v[1], 42);
+    }
+
+    #[test]
+    fn test_unsafe_update_volatile() {
+        let mut v: [u32; 3] = [1, 2, 3];
+        let mut a = unsafe { UnsafeReadWrite::<u32, M>::new(addr(&mut v), 3, M) };
+
+        unsafe { a.unsafe_at_mut(2).unsafe_update_volatile(|v| *v *= 10) };
+        assert_eq!(v[2], 30);
+    }
+
+    #[test]
+    fn test_element_from_ptr() {
+        let mut v: u32 = 42;
+        let mut e = unsafe {
+            Element::<u32, marker::ReadWrite>::from_ptr(NonNull::new(&mut v as *mut u32).unwrap())
+        };
+
+        assert_eq!(e.read_volatile(), 42);
+        e.write_volatile(84);
+        assert_eq!(v, 84);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_element_from_ptr_not_aligned() {
+        let mut v: [u32; 2] = [0; 2];
+        let ptr = unsafe { v.as_mut_ptr().cast::<u8>().add(1).cast::<u32>() };
+
+        let _ = unsafe { Element::<u32, marker::ReadWrite>::from_ptr(NonNull::new(ptr).unwrap()) };
+    }
+
+    #[test]
+    fn test_err_not_aligned() {
+        let mut v: [u32; 3] = [0; 3];
+
+        let r = unsafe { ReadWrite::<u32, M>::try_new(addr(&mut v) + 1, 3, M) };
+        assert_eq!(
+            r.err(),
+            Some(Error::NotAligned {
+                alignment: mem::align_of::<u32>(),
+                address: addr(&mut v) + 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_err_stride_not_aligned() {
+        let mut v: [u32; 3] = [0; 3];
+
+        let r = unsafe { ReadWrite::<u32, M>::try_new_strided(addr(&mut v), 3, 6, M) };
+        assert_eq!(
+            r.err(),
+            Some(Error::NotAligned {
+                alignment: mem::align_of::<u32>(),
+                address: 6,
+            })
+        );
+    }
+
+    fn addr<T>(v: &mut T) -> usize {
+        let v: *mut T = v;
+
+        v as usize
+    }
+}
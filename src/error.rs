@@ -0,0 +1,30 @@
+//! Error types returned by this crate.
+
+use core::fmt;
+
+/// An error that can occur when constructing an accessor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The given physical address, or the element stride of an array accessor, is not aligned as
+    /// the element type requires.
+    NotAligned {
+        /// The alignment that the address was expected to satisfy.
+        alignment: usize,
+        /// The address (or stride) that failed the alignment check.
+        address: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotAligned { alignment, address } => {
+                write!(
+                    f,
+                    "the address {:#x} is not aligned to {}",
+                    address, alignment
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! Accessors to read, modify, and write values of arbitrary memory, including MMIO.
+//!
+//! This crate does not depend on `std`, and can be used for OS development.
+
+#![no_std]
+
+pub mod array;
+pub mod error;
+pub mod mapper;
+pub mod marker;
+pub mod single;
+
+#[doc(inline)]
+pub use accessor_macros::BoundedStructuralOf;
+
+/// Returns `true` if `addr` is aligned as the type `T` requires.
+fn is_aligned<T>(addr: usize) -> bool {
+    addr.is_multiple_of(core::mem::align_of::<T>())
+}
@@ -0,0 +1,38 @@
+//! Traits and types for mapping physical memory into the virtual address space.
+
+use core::num::NonZeroUsize;
+
+/// A type that maps a region of physical memory into the virtual address space, so that
+/// accessors in this crate can dereference the resulting pointers.
+///
+/// This trait is intended to be implemented by the user of this crate; the implementation
+/// usually talks to the platform's paging code (or, on platforms without paging, does nothing).
+pub trait Mapper {
+    /// Maps `bytes` bytes of physical memory starting at `phys_start`, and returns the virtual
+    /// address at which the memory is now accessible.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the physical memory region `phys_start..phys_start + bytes`
+    /// is valid to access for as long as the mapping returned by this call is in use.
+    unsafe fn map(&mut self, phys_start: usize, bytes: usize) -> NonZeroUsize;
+
+    /// Unmaps `bytes` bytes of memory starting at the virtual address `virt_start`, which must
+    /// have been returned by a previous call to [`Mapper::map`] on `self`.
+    fn unmap(&mut self, virt_start: usize, bytes: usize);
+}
+
+/// A [`Mapper`] that performs no mapping at all: the physical address is used unchanged as the
+/// virtual address.
+///
+/// This is useful on platforms that do not use paging, or where the memory in question is
+/// already mapped one-to-one.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Identity;
+impl Mapper for Identity {
+    unsafe fn map(&mut self, phys_start: usize, _bytes: usize) -> NonZeroUsize {
+        NonZeroUsize::new(phys_start).expect("phys_start must not be 0")
+    }
+
+    fn unmap(&mut self, _virt_start: usize, _bytes: usize) {}
+}
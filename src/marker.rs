@@ -0,0 +1,152 @@
+//! Marker types that describe the access permissions of an accessor.
+
+use core::marker::PhantomData;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Implemented by every marker type that can be used as the `A` type parameter of an accessor
+/// (e.g. [`single::Generic`] or [`array::Generic`]).
+///
+/// This trait is sealed; it cannot be implemented outside of this crate.
+///
+/// [`single::Generic`]: crate::single::Generic
+/// [`array::Generic`]: crate::array::Generic
+pub trait AccessorTypeSpecifier: sealed::Sealed {}
+
+/// Implemented by marker types that grant safe read access.
+pub trait Readable: AccessorTypeSpecifier {}
+
+/// Implemented by marker types that grant safe write access.
+pub trait Writable: AccessorTypeSpecifier {}
+
+/// Implemented by marker types that grant read access which is only sound to perform inside an
+/// `unsafe` block, typically because reading has a side effect (e.g. a FIFO register that pops
+/// an element on every read).
+///
+/// A marker type implements either [`Readable`] or `UnsafeReadable`, never both: if a read is
+/// safe, [`Readable`] already says so, and there is no need for a second, unsafe-only entry
+/// point to the same operation.
+pub trait UnsafeReadable: AccessorTypeSpecifier {}
+
+/// Implemented by marker types that grant write access which is only sound to perform inside an
+/// `unsafe` block, typically because writing has a side effect (e.g. a status register that
+/// clears bits on every write).
+///
+/// A marker type implements either [`Writable`] or `UnsafeWritable`, never both; see
+/// [`UnsafeReadable`] for why.
+pub trait UnsafeWritable: AccessorTypeSpecifier {}
+
+/// The permission levels that [`Access`] composes along its read and write axes.
+pub mod permission {
+    mod sealed {
+        pub trait Sealed {}
+    }
+
+    /// Implemented by the marker types usable as a type parameter of [`Access`](super::Access).
+    ///
+    /// This trait is sealed; it cannot be implemented outside of this crate.
+    pub trait Permission: sealed::Sealed {}
+
+    /// Grants safe access.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct Safe;
+    impl sealed::Sealed for Safe {}
+    impl Permission for Safe {}
+
+    /// Grants access that is only sound to perform inside an `unsafe` block.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct Unsafe;
+    impl sealed::Sealed for Unsafe {}
+    impl Permission for Unsafe {}
+
+    /// Grants no access at all.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub struct NoAccess;
+    impl sealed::Sealed for NoAccess {}
+    impl Permission for NoAccess {}
+}
+
+/// A marker type that grants `R` read access and `W` write access.
+///
+/// This composes the [`Safe`], [`Unsafe`], and [`NoAccess`] permission levels along the read and
+/// write axes, so that a new combination (e.g. safe to read, but only sound to write inside an
+/// `unsafe` block) does not need its own ad-hoc marker struct. [`ReadWrite`], [`ReadOnly`], and
+/// [`WriteOnly`] are themselves just aliases of `Access` instantiated with [`Safe`] and
+/// [`NoAccess`].
+///
+/// [`Safe`]: permission::Safe
+/// [`Unsafe`]: permission::Unsafe
+/// [`NoAccess`]: permission::NoAccess
+pub struct Access<R, W> {
+    _read: PhantomData<R>,
+    _write: PhantomData<W>,
+}
+impl<R, W> Clone for Access<R, W> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<R, W> Copy for Access<R, W> {}
+impl<R, W> PartialEq for Access<R, W> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+impl<R, W> Eq for Access<R, W> {}
+impl<R, W> Default for Access<R, W> {
+    fn default() -> Self {
+        Self {
+            _read: PhantomData,
+            _write: PhantomData,
+        }
+    }
+}
+impl<R, W> core::fmt::Debug for Access<R, W> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Access").finish()
+    }
+}
+impl<R, W> sealed::Sealed for Access<R, W>
+where
+    R: permission::Permission,
+    W: permission::Permission,
+{
+}
+impl<R, W> AccessorTypeSpecifier for Access<R, W>
+where
+    R: permission::Permission,
+    W: permission::Permission,
+{
+}
+impl<W> Readable for Access<permission::Safe, W> where W: permission::Permission {}
+impl<R> Writable for Access<R, permission::Safe> where R: permission::Permission {}
+impl<W> UnsafeReadable for Access<permission::Unsafe, W> where W: permission::Permission {}
+impl<R> UnsafeWritable for Access<R, permission::Unsafe> where R: permission::Permission {}
+
+/// A marker type that grants both safe read and safe write access.
+pub type ReadWrite = Access<permission::Safe, permission::Safe>;
+
+/// A marker type that grants safe read access only.
+pub type ReadOnly = Access<permission::Safe, permission::NoAccess>;
+
+/// A marker type that grants safe write access only.
+pub type WriteOnly = Access<permission::NoAccess, permission::Safe>;
+
+/// A marker type that grants read access only, and only inside an `unsafe` block (e.g. a FIFO
+/// register that pops an element on every read).
+pub type UnsafeReadOnly = Access<permission::Unsafe, permission::NoAccess>;
+
+/// A marker type that grants write access only, and only inside an `unsafe` block (e.g. a status
+/// register that clears bits on every write).
+pub type UnsafeWriteOnly = Access<permission::NoAccess, permission::Unsafe>;
+
+/// A marker type that grants neither read nor write access, safe or otherwise.
+///
+/// This is useful for registers that an accessor must be constructed over but that must never
+/// actually be touched. The `#[access(..)]` attribute of [`BoundedStructuralOf`] does not accept
+/// this mode; it takes only `ReadWrite`, `ReadOnly`, and `WriteOnly`.
+///
+/// [`BoundedStructuralOf`]: crate::BoundedStructuralOf
+pub type NoAccess = Access<permission::NoAccess, permission::NoAccess>;
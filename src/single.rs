@@ -4,9 +4,9 @@ use {
     crate::{
         error::Error,
         mapper::Mapper,
-        marker::{self, AccessorTypeSpecifier, Readable, Writable},
+        marker::{self, AccessorTypeSpecifier, Readable, UnsafeReadable, UnsafeWritable, Writable},
     },
-    core::{fmt, hash::Hash, marker::PhantomData, mem, ptr},
+    core::{cell::UnsafeCell, fmt, hash::Hash, marker::PhantomData, mem, ptr, ptr::NonNull},
 };
 
 /// An alias of [`ReadWrite`].
@@ -22,10 +22,27 @@ pub type ReadOnly<T, M> = Generic<T, M, marker::ReadOnly>;
 /// A write-only accessor.
 pub type WriteOnly<T, M> = Generic<T, M, marker::WriteOnly>;
 
+/// An accessor that is readable, but only inside an `unsafe` block (e.g. a FIFO register that
+/// pops an element on every read).
+pub type UnsafeReadOnly<T, M> = Generic<T, M, marker::UnsafeReadOnly>;
+
+/// An accessor that is writable, but only inside an `unsafe` block (e.g. a status register that
+/// clears bits on every write).
+pub type UnsafeWriteOnly<T, M> = Generic<T, M, marker::UnsafeWriteOnly>;
+
+/// An accessor that is neither readable nor writable, safe or otherwise.
+pub type NoAccess<T, M> = Generic<T, M, marker::NoAccess>;
+
 /// Combined with proc-macro [`BoundedStructuralOf`], this trait converts array accessors of field struct types into a struct of accessors with same field names.
 ///
 /// This trait is intended to be implemented automatically by [`BoundedStructuralOf`] macro expansion. Users should not implement this manually.
 ///
+/// Each field may carry an `#[access(ReadOnly)]`, `#[access(WriteOnly)]`, or `#[access(ReadWrite)]`
+/// attribute to pick the marker type of its generated accessor; a field with no `#[access(..)]`
+/// attribute defaults to `ReadWrite`. This lets a derived struct model a real MMIO register block
+/// where, say, an input data register is read-only and an output data register is write-only, and
+/// get a compile error out of `structural().idr.write_volatile(..)` rather than a runtime surprise.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -36,27 +53,28 @@ pub type WriteOnly<T, M> = Generic<T, M, marker::WriteOnly>;
 /// #[repr(C)]
 /// #[derive(Clone, Copy, BoundedStructuralOf)]
 /// struct Foo {
+///     #[access(ReadOnly)]
 ///     x: u32,
 ///     y: u32,
 /// }
 ///
 /// // The above derivation creates a struct-of-accessor type called `BoundedStructuralOfFoo` which is roughly equivalent to:
 /// // ```
-/// // struct BoundedStructuralOfFoo {
-/// //     x: accessor::single::ReadWrite::<u32, Identity>,
-/// //     y: accessor::single::ReadWrite::<u32, Identity>,
+/// // struct BoundedStructuralOfFoo<'a> {
+/// //     x: accessor::single::Borrowed::<'a, u32, accessor::marker::ReadOnly>,
+/// //     y: accessor::single::Borrowed::<'a, u32, accessor::marker::ReadWrite>,
 /// // }
 /// // ```
 /// // The derivation also implements `BoundedStructural<Foo, M, A>` and `BoundedStructuralMut<Foo, M, A>` so that an `accessor::single::ReadWrite::<Foo, M>` instance
 /// // can be accessed with a `BoundedStructuralOfFoo` item, which has a lifetime bound to the base accessor.
 ///
-/// let mut a = unsafe { accessor::single::ReadWrite::<Foo, M>::new(0x1000, Identity) };
+/// let mut a = unsafe { accessor::single::ReadWrite::<Foo, Identity>::new(0x1000, Identity) };
 ///
 /// // read `x` field of the accessor.
 /// let x = a.structural().x.read_volatile();
 ///
 /// // write 5 as the `y` field of the accessor.
-/// a.structural_at_mut(2).y.write_volatile(5);
+/// a.structural_mut().y.write_volatile(5);
 ///
 /// ```
 ///
@@ -90,6 +108,136 @@ where
     fn structural_mut(&mut self) -> Self::BoundedStructuralType<'_>;
 }
 
+/// A borrowed accessor to an already-mapped element, returned by [`Borrowed::from_ptr`].
+///
+/// Unlike [`Generic`], this does not own a [`Mapper`] and performs no unmapping on drop; it
+/// simply borrows memory that the caller already knows is mapped (e.g. a single field within a
+/// larger `#[repr(C)]` block that was mapped once as a whole, rather than re-mapped per field).
+pub struct Borrowed<'a, T, A> {
+    ptr: NonNull<UnsafeCell<T>>,
+    _readable_writable: PhantomData<A>,
+    _life: PhantomData<&'a ()>,
+}
+impl<'a, T, A> Borrowed<'a, T, A> {
+    /// Creates a borrowed accessor to an element of type `T` at the virtual address `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure the following conditions:
+    /// - `ptr` is valid for reads and writes of a `T` for as long as the returned accessor lives.
+    /// - Any other accessors except the one returned by this method must not access the value
+    ///   while the returned one lives.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `ptr` is not aligned as the type `T` requires.
+    pub unsafe fn from_ptr(ptr: NonNull<T>) -> Self {
+        assert!(super::is_aligned::<T>(ptr.as_ptr() as usize));
+
+        Self {
+            ptr: ptr.cast(),
+            _readable_writable: PhantomData,
+            _life: PhantomData,
+        }
+    }
+}
+impl<'a, T, A> Borrowed<'a, T, A>
+where
+    A: Readable,
+{
+    /// Reads a value from the address that this accessor points to.
+    pub fn read_volatile(&self) -> T {
+        // SAFETY: `Borrowed::from_ptr` ensures that `self.ptr` is aligned properly and points to
+        // a valid `T`. Going through `UnsafeCell::raw_get` avoids manufacturing a `&T` that could
+        // alias with a write performed through another mapping of the same memory.
+        unsafe { ptr::read_volatile(UnsafeCell::raw_get(self.ptr.as_ptr())) }
+    }
+}
+impl<'a, T, A> Borrowed<'a, T, A>
+where
+    A: Writable,
+{
+    /// Writes a value to the address that this accessor points to.
+    pub fn write_volatile(&mut self, v: T) {
+        // SAFETY: see `Borrowed::read_volatile`.
+        unsafe {
+            ptr::write_volatile(UnsafeCell::raw_get(self.ptr.as_ptr()), v);
+        }
+    }
+}
+impl<'a, T, A> Borrowed<'a, T, A>
+where
+    A: Readable + Writable,
+{
+    /// Updates a value that this accessor points to by reading it, modifying it, and writing it
+    /// back.
+    pub fn update_volatile<U>(&mut self, f: U)
+    where
+        U: FnOnce(&mut T),
+    {
+        let mut v = self.read_volatile();
+        f(&mut v);
+        self.write_volatile(v);
+    }
+}
+impl<'a, T, A> Borrowed<'a, T, A>
+where
+    A: UnsafeReadable,
+{
+    /// Reads a value from the address that this accessor points to.
+    ///
+    /// # Safety
+    ///
+    /// The marker type `A` grants this access because reading has a side effect (e.g. a FIFO
+    /// register that pops an element on every read). The caller must ensure that performing
+    /// this side effect is sound.
+    pub unsafe fn unsafe_read_volatile(&self) -> T {
+        // SAFETY: `Borrowed::from_ptr` ensures that `self.ptr` is aligned properly and points to
+        // a valid `T`. The caller upholds the side-effect safety of the read itself.
+        unsafe { ptr::read_volatile(UnsafeCell::raw_get(self.ptr.as_ptr())) }
+    }
+}
+impl<'a, T, A> Borrowed<'a, T, A>
+where
+    A: UnsafeWritable,
+{
+    /// Writes a value to the address that this accessor points to.
+    ///
+    /// # Safety
+    ///
+    /// The marker type `A` grants this access because writing has a side effect (e.g. a status
+    /// register that clears bits on every write). The caller must ensure that performing this
+    /// side effect is sound.
+    pub unsafe fn unsafe_write_volatile(&mut self, v: T) {
+        // SAFETY: see `Borrowed::unsafe_read_volatile`.
+        unsafe {
+            ptr::write_volatile(UnsafeCell::raw_get(self.ptr.as_ptr()), v);
+        }
+    }
+}
+impl<'a, T, A> Borrowed<'a, T, A>
+where
+    A: UnsafeReadable + UnsafeWritable,
+{
+    /// Updates a value that this accessor points to by reading it, modifying it, and writing it
+    /// back.
+    ///
+    /// # Safety
+    ///
+    /// See the safety requirements of [`Borrowed::unsafe_read_volatile`] and
+    /// [`Borrowed::unsafe_write_volatile`].
+    pub unsafe fn unsafe_update_volatile<U>(&mut self, f: U)
+    where
+        U: FnOnce(&mut T),
+    {
+        // SAFETY: the caller upholds the safety requirements of both the read and the write.
+        let mut v = unsafe { self.unsafe_read_volatile() };
+        f(&mut v);
+        // SAFETY: see above.
+        unsafe { self.unsafe_write_volatile(v) };
+    }
+}
+
 /// An accessor to read, modify, and write a single value of memory.
 ///
 /// `T` does not need to implement [`Copy`]. However, be careful that [`Generic::read_volatile`]
@@ -134,8 +282,7 @@ where
     M: Mapper,
     A: AccessorTypeSpecifier,
 {
-    virt: usize,
-    _marker: PhantomData<T>,
+    ptr: NonNull<UnsafeCell<T>>,
     _readable_writable: PhantomData<A>,
     mapper: M,
 }
@@ -151,7 +298,7 @@ where
     /// The caller must ensure the following conditions:
     /// - The value at the physical address `phys_base` is valid.
     /// - Any other accessors except the one returned by this method must not access the value
-    /// while the returned one lives.
+    ///   while the returned one lives.
     ///
     /// # Panics
     ///
@@ -160,11 +307,13 @@ where
         assert!(super::is_aligned::<T>(phys_base));
 
         let bytes = mem::size_of::<T>();
-        let virt = mapper.map(phys_base, bytes).get();
+        let virt = mapper.map(phys_base, bytes);
+
+        // SAFETY: `Mapper::map` returns a `NonZeroUsize`, so the resulting pointer is never null.
+        let ptr = unsafe { NonNull::new_unchecked(virt.get() as *mut UnsafeCell<T>) };
 
         Self {
-            virt,
-            _marker: PhantomData,
+            ptr,
             _readable_writable: PhantomData,
             mapper,
         }
@@ -177,7 +326,7 @@ where
     /// The caller must ensure the following conditions:
     /// - The value at the physical address `phys_base` is valid.
     /// - Any other accessors except the one returned by this method must not access the value
-    /// while the returned one lives.
+    ///   while the returned one lives.
     ///
     /// # Errors
     ///
@@ -194,13 +343,15 @@ where
         }
     }
 
-    /// Returns the virtual address of the item.
+    /// Returns a pointer to the item.
     ///
-    /// This is public but hidden, since this method should be called in `accessor_macros::BoundedStructuralOf` proc-macro expansion.
-    /// Users of this crate are not intended to call this directly.
+    /// This is public but hidden, since this method should be called in
+    /// `accessor_macros::BoundedStructuralOf` proc-macro expansion to derive per-field pointers
+    /// without losing the provenance of `self.ptr`. Users of this crate are not intended to call
+    /// this directly.
     #[doc(hidden)]
-    pub unsafe fn addr(&self) -> usize {
-        self.virt
+    pub unsafe fn ptr(&self) -> NonNull<u8> {
+        self.ptr.cast()
     }
 }
 impl<T, M, A> Generic<T, M, A>
@@ -210,8 +361,10 @@ where
 {
     /// Reads a value from the address that the accessor points to.
     pub fn read_volatile(&self) -> T {
-        // SAFETY: `Accessor::new` ensures that `self.virt` is aligned properly.
-        unsafe { ptr::read_volatile(self.virt as *const _) }
+        // SAFETY: `Generic::new` ensures that `self.ptr` is aligned properly and points to a
+        // valid `T`. Going through `UnsafeCell::raw_get` avoids manufacturing a `&T` that could
+        // alias with a write performed through another mapping of the same memory.
+        unsafe { ptr::read_volatile(UnsafeCell::raw_get(self.ptr.as_ptr())) }
     }
 
     /// Alias of [`Generic::read_volatile`].
@@ -227,9 +380,11 @@ where
 {
     /// Writes a value to the address that the accessor points to.
     pub fn write_volatile(&mut self, v: T) {
-        // SAFETY: `Accessor::new` ensures that `self.virt` is aligned properly.
+        // SAFETY: `Generic::new` ensures that `self.ptr` is aligned properly and points to a
+        // valid `T`. Going through `UnsafeCell::raw_get` avoids manufacturing a `&mut T` that
+        // could alias with an access performed through another mapping of the same memory.
         unsafe {
-            ptr::write_volatile(self.virt as *mut _, v);
+            ptr::write_volatile(UnsafeCell::raw_get(self.ptr.as_ptr()), v);
         }
     }
 
@@ -240,6 +395,67 @@ where
     }
 }
 impl<T, M, A> Generic<T, M, A>
+where
+    M: Mapper,
+    A: UnsafeReadable,
+{
+    /// Reads a value from the address that the accessor points to.
+    ///
+    /// # Safety
+    ///
+    /// The marker type `A` grants this access because reading has a side effect (e.g. a FIFO
+    /// register that pops an element on every read). The caller must ensure that performing
+    /// this side effect is sound.
+    pub unsafe fn unsafe_read_volatile(&self) -> T {
+        // SAFETY: `Generic::new` ensures that `self.ptr` is aligned properly and points to a
+        // valid `T`. The caller upholds the side-effect safety of the read itself.
+        unsafe { ptr::read_volatile(UnsafeCell::raw_get(self.ptr.as_ptr())) }
+    }
+}
+impl<T, M, A> Generic<T, M, A>
+where
+    M: Mapper,
+    A: UnsafeWritable,
+{
+    /// Writes a value to the address that the accessor points to.
+    ///
+    /// # Safety
+    ///
+    /// The marker type `A` grants this access because writing has a side effect (e.g. a status
+    /// register that clears bits on every write). The caller must ensure that performing this
+    /// side effect is sound.
+    pub unsafe fn unsafe_write_volatile(&mut self, v: T) {
+        // SAFETY: `Generic::new` ensures that `self.ptr` is aligned properly and points to a
+        // valid `T`. The caller upholds the side-effect safety of the write itself.
+        unsafe {
+            ptr::write_volatile(UnsafeCell::raw_get(self.ptr.as_ptr()), v);
+        }
+    }
+}
+impl<T, M, A> Generic<T, M, A>
+where
+    M: Mapper,
+    A: UnsafeReadable + UnsafeWritable,
+{
+    /// Updates a value that the accessor points to by reading it, modifying it, and writing it
+    /// back.
+    ///
+    /// # Safety
+    ///
+    /// See the safety requirements of [`Generic::unsafe_read_volatile`] and
+    /// [`Generic::unsafe_write_volatile`].
+    pub unsafe fn unsafe_update_volatile<U>(&mut self, f: U)
+    where
+        U: FnOnce(&mut T),
+    {
+        // SAFETY: the caller upholds the safety requirements of both the read and the write.
+        let mut v = unsafe { self.unsafe_read_volatile() };
+        f(&mut v);
+        // SAFETY: see above.
+        unsafe { self.unsafe_write_volatile(v) };
+    }
+}
+impl<T, M, A> Generic<T, M, A>
 where
     M: Mapper,
     A: Readable + Writable,
@@ -331,7 +547,7 @@ where
 {
     fn drop(&mut self) {
         let bytes = mem::size_of::<T>();
-        self.mapper.unmap(self.virt, bytes);
+        self.mapper.unmap(self.ptr.as_ptr() as usize, bytes);
     }
 }
 
@@ -375,6 +591,35 @@ mod tests {
         assert_eq!(v, 84);
     }
 
+    type UnsafeReadWrite<T, M> =
+        Generic<T, M, marker::Access<marker::permission::Unsafe, marker::permission::Unsafe>>;
+
+    #[test]
+    fn test_unsafe_read_volatile() {
+        let v: u32 = 42;
+        let a = unsafe { UnsafeReadWrite::<u32, M>::new(addr(&v), M) };
+
+        assert_eq!(unsafe { a.unsafe_read_volatile() }, 42);
+    }
+
+    #[test]
+    fn test_unsafe_write_volatile() {
+        let mut v: u32 = 0;
+        let mut a = unsafe { UnsafeReadWrite::<u32, M>::new(addr(&mut v), M) };
+
+        unsafe { a.unsafe_write_volatile(42) };
+        assert_eq!(v, 42);
+    }
+
+    #[test]
+    fn test_unsafe_update_volatile() {
+        let mut v: u32 = 42;
+        let mut a = unsafe { UnsafeReadWrite::<u32, M>::new(addr(&mut v), M) };
+
+        unsafe { a.unsafe_update_volatile(|v| *v *= 2) };
+        assert_eq!(v, 84);
+    }
+
     #[test]
     #[should_panic]
     fn test_not_aligned() {
@@ -383,6 +628,29 @@ mod tests {
         let _ = unsafe { ReadWrite::<u32, M>::new(addr(&v) + 1, M) };
     }
 
+    type BorrowedReadWrite<'a, T> = Borrowed<'a, T, marker::ReadWrite>;
+
+    #[test]
+    fn test_borrowed_read_write_volatile() {
+        let mut v: u32 = 42;
+        let mut a = unsafe {
+            BorrowedReadWrite::from_ptr(NonNull::new(&mut v as *mut u32).unwrap())
+        };
+
+        assert_eq!(a.read_volatile(), 42);
+        a.write_volatile(84);
+        assert_eq!(v, 84);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_borrowed_not_aligned() {
+        let mut v: [u32; 2] = [0; 2];
+        let ptr = unsafe { v.as_mut_ptr().cast::<u8>().add(1).cast::<u32>() };
+
+        let _ = unsafe { BorrowedReadWrite::from_ptr(NonNull::new(ptr).unwrap()) };
+    }
+
     #[test]
     fn test_err_not_aligned() {
         let v: u32 = 42;
@@ -0,0 +1,50 @@
+//! Integration test for the `#[access(..)]` attribute of `BoundSetGenericOf`.
+
+use accessor::array::{BoundSetGeneric, BoundSetGenericMut, BoundSetGenericOf};
+use accessor::mapper::Mapper;
+use core::num::NonZeroUsize;
+
+#[repr(C)]
+#[derive(Clone, Copy, BoundSetGenericOf)]
+struct Regs {
+    #[access(ReadOnly)]
+    status: u32,
+    #[access(WriteOnly)]
+    command: u32,
+    scratch: u32,
+}
+
+struct M;
+impl Mapper for M {
+    unsafe fn map(&mut self, phys_start: usize, _bytes: usize) -> NonZeroUsize {
+        NonZeroUsize::new(phys_start).unwrap()
+    }
+
+    fn unmap(&mut self, _virt_start: usize, _bytes: usize) {}
+}
+
+#[test]
+fn test_mixed_access_fields() {
+    let mut regs = [
+        Regs {
+            status: 1,
+            command: 0,
+            scratch: 0,
+        },
+        Regs {
+            status: 2,
+            command: 0,
+            scratch: 0,
+        },
+    ];
+    let mut a = unsafe {
+        accessor::array::ReadWrite::<Regs, M>::new(regs.as_mut_ptr() as usize, regs.len(), M)
+    };
+
+    assert_eq!(a.set_at(1).status.read_volatile(), 2);
+    a.set_at_mut(0).command.write_volatile(42);
+    assert_eq!(regs[0].command, 42);
+
+    a.set_at_mut(1).scratch.write_volatile(7);
+    assert_eq!(a.set_at(1).scratch.read_volatile(), 7);
+}
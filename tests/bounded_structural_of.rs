@@ -0,0 +1,44 @@
+//! Integration test for the `#[access(..)]` attribute of `BoundedStructuralOf`.
+
+use accessor::mapper::Mapper;
+use accessor::single::{BoundedStructural, BoundedStructuralMut};
+use accessor::BoundedStructuralOf;
+use core::num::NonZeroUsize;
+
+#[repr(C)]
+#[derive(Clone, Copy, BoundedStructuralOf)]
+struct Regs {
+    #[access(ReadOnly)]
+    status: u32,
+    #[access(WriteOnly)]
+    command: u32,
+    scratch: u32,
+}
+
+struct M;
+impl Mapper for M {
+    unsafe fn map(&mut self, phys_start: usize, _bytes: usize) -> NonZeroUsize {
+        NonZeroUsize::new(phys_start).unwrap()
+    }
+
+    fn unmap(&mut self, _virt_start: usize, _bytes: usize) {}
+}
+
+#[test]
+fn test_mixed_access_fields() {
+    let mut regs = Regs {
+        status: 1,
+        command: 0,
+        scratch: 0,
+    };
+    let mut a = unsafe {
+        accessor::single::ReadWrite::<Regs, M>::new(&mut regs as *mut Regs as usize, M)
+    };
+
+    assert_eq!(a.structural().status.read_volatile(), 1);
+    a.structural_mut().command.write_volatile(42);
+    assert_eq!(regs.command, 42);
+
+    a.structural_mut().scratch.write_volatile(7);
+    assert_eq!(a.structural().scratch.read_volatile(), 7);
+}
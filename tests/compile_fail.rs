@@ -0,0 +1,8 @@
+//! Compile-fail tests for the `#[access(..)]` attribute of `BoundedStructuralOf`: writing to a
+//! `#[access(ReadOnly)]` field must be rejected at compile time, not discovered at run time.
+
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/*.rs");
+}
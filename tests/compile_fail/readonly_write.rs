@@ -0,0 +1,19 @@
+use accessor::mapper::Identity;
+use accessor::single::BoundedStructuralMut;
+use accessor::BoundedStructuralOf;
+
+#[repr(C)]
+#[derive(Clone, Copy, BoundedStructuralOf)]
+struct Regs {
+    #[access(ReadOnly)]
+    status: u32,
+}
+
+fn main() {
+    let mut regs = Regs { status: 42 };
+    let mut a = unsafe {
+        accessor::single::ReadWrite::<Regs, Identity>::new(&mut regs as *mut Regs as usize, Identity)
+    };
+
+    a.structural_mut().status.write_volatile(0);
+}